@@ -0,0 +1,127 @@
+//! Local CLI companion for Money Machine.
+//!
+//! Fetches stored API keys from the running GUI app and execs external
+//! trading scripts with them injected as environment variables, so secrets
+//! never touch shell history or config files. Talks to the GUI over a local
+//! socket; the GUI refuses requests while its vault is locked.
+//!
+//! Unix-only: the GUI's CLI companion socket is a Unix domain socket (see
+//! `money_machine_lib::ipc`), so this binary has nothing to dial on Windows;
+//! there `main` just prints that the companion isn't supported.
+
+#[cfg(unix)]
+use money_machine_lib::ipc::{socket_path, IpcRequest, IpcResponse};
+#[cfg(unix)]
+use std::io::{BufRead, BufReader, Write};
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
+#[cfg(unix)]
+use std::process::Command;
+
+#[cfg(unix)]
+fn send_request(request: &IpcRequest) -> Result<String, String> {
+    let stream = UnixStream::connect(socket_path())
+        .map_err(|e| format!("Failed to reach Money Machine (is it running?): {}", e))?;
+    let mut writer = stream.try_clone().map_err(|e| e.to_string())?;
+
+    let mut payload = serde_json::to_string(request).map_err(|e| e.to_string())?;
+    payload.push('\n');
+    writer.write_all(payload.as_bytes()).map_err(|e| e.to_string())?;
+
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line).map_err(|e| e.to_string())?;
+
+    match serde_json::from_str::<IpcResponse>(&line).map_err(|e| e.to_string())? {
+        IpcResponse::Ok(value) => Ok(value),
+        IpcResponse::Err(message) => Err(message),
+    }
+}
+
+#[cfg(unix)]
+fn cmd_get(key_name: String) -> Result<(), String> {
+    let value = send_request(&IpcRequest::Get { key_name })?;
+    println!("{}", value);
+    Ok(())
+}
+
+#[cfg(unix)]
+fn cmd_store(key_name: String) -> Result<(), String> {
+    let mut key_value = String::new();
+    std::io::stdin()
+        .read_line(&mut key_value)
+        .map_err(|e| format!("Failed to read key from stdin: {}", e))?;
+    let key_value = key_value.trim_end_matches(['\r', '\n']).to_string();
+
+    send_request(&IpcRequest::Store { key_name, key_value }).map(|_| ())
+}
+
+/// `keys` are the names to inject as env vars; `command` is the program and its args.
+#[cfg(unix)]
+fn cmd_exec(keys: Vec<String>, command: Vec<String>) -> Result<(), String> {
+    let (program, args) = command
+        .split_first()
+        .ok_or_else(|| "exec requires a command to run after `--`".to_string())?;
+    let resolved =
+        which::which(program).map_err(|e| format!("Failed to resolve '{}': {}", program, e))?;
+
+    let mut cmd = Command::new(resolved);
+    cmd.args(args);
+
+    for key_name in keys {
+        let value = send_request(&IpcRequest::Get {
+            key_name: key_name.clone(),
+        })?;
+        cmd.env(key_name, value);
+    }
+
+    let status = cmd
+        .status()
+        .map_err(|e| format!("Failed to launch '{}': {}", program, e))?;
+    std::process::exit(status.code().unwrap_or(1));
+}
+
+#[cfg(unix)]
+fn print_usage() {
+    eprintln!("Usage:");
+    eprintln!("  money-machine-cli get <key_name>");
+    eprintln!("  money-machine-cli store <key_name>        (reads the value from stdin)");
+    eprintln!("  money-machine-cli exec [key_names...] -- <cmd> [args...]");
+}
+
+#[cfg(unix)]
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let result = match args.next().as_deref() {
+        Some("get") => match args.next() {
+            Some(key_name) => cmd_get(key_name),
+            None => Err("get requires <key_name>".to_string()),
+        },
+        Some("store") => match args.next() {
+            Some(key_name) => cmd_store(key_name),
+            None => Err("store requires <key_name>".to_string()),
+        },
+        Some("exec") => {
+            let rest: Vec<String> = args.collect();
+            match rest.iter().position(|arg| arg == "--") {
+                Some(sep) => cmd_exec(rest[..sep].to_vec(), rest[sep + 1..].to_vec()),
+                None => Err("exec requires `--` before the command to run".to_string()),
+            }
+        }
+        _ => {
+            print_usage();
+            std::process::exit(1);
+        }
+    };
+
+    if let Err(message) = result {
+        eprintln!("Error: {}", message);
+        std::process::exit(1);
+    }
+}
+
+#[cfg(not(unix))]
+fn main() {
+    eprintln!("money-machine-cli is not supported on this platform");
+    std::process::exit(1);
+}
@@ -0,0 +1,67 @@
+//! Wire format for the local socket that lets the `money-machine-cli`
+//! companion binary reach the running GUI app without ever putting secrets
+//! in shell history or config files.
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+pub const SOCKET_NAME: &str = "money-machine.sock";
+
+/// Largest line the IPC server will read before giving up on a connection,
+/// so a peer that never sends a newline can't grow a buffer unbounded.
+pub const MAX_LINE_BYTES: u64 = 64 * 1024;
+
+/// A private, per-user directory to bind the socket in. Prefers
+/// `XDG_RUNTIME_DIR` (mode 0700, owned by the user, wiped on logout); unlike
+/// the shared, world-writable OS temp dir, another local user can't pre-create
+/// a file at this path out from under us.
+fn socket_dir() -> PathBuf {
+    if let Ok(runtime_dir) = std::env::var("XDG_RUNTIME_DIR") {
+        return PathBuf::from(runtime_dir).join("money-machine");
+    }
+
+    let mut dir = std::env::temp_dir();
+    dir.push(format!("money-machine-{}", current_uid()));
+    dir
+}
+
+/// Where the GUI binds its listener and the CLI dials in.
+pub fn socket_path() -> PathBuf {
+    socket_dir().join(SOCKET_NAME)
+}
+
+/// Creates the socket directory (if needed) locked down to the current user
+/// only, so no other local account can place a file at `socket_path()`.
+pub fn ensure_socket_dir() -> std::io::Result<PathBuf> {
+    let dir = socket_dir();
+    std::fs::create_dir_all(&dir)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o700))?;
+    }
+    Ok(dir)
+}
+
+#[cfg(unix)]
+fn current_uid() -> u32 {
+    // SAFETY: getuid() takes no arguments and always succeeds.
+    unsafe { libc::getuid() }
+}
+
+#[cfg(not(unix))]
+fn current_uid() -> u32 {
+    0
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum IpcRequest {
+    Get { key_name: String },
+    Store { key_name: String, key_value: String },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum IpcResponse {
+    Ok(String),
+    Err(String),
+}
@@ -1,13 +1,27 @@
 use tauri::{
-    menu::{Menu, MenuItem},
+    menu::{CheckMenuItem, Menu, MenuItem},
     tray::{MouseButton, TrayIconBuilder, TrayIconEvent},
     Manager,
 };
 
+pub mod ipc;
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_updater::Builder::new().build())
+        .plugin(
+            tauri_plugin_global_shortcut::Builder::new()
+                .with_handler(|app, shortcut, event| {
+                    if event.state() == ShortcutState::Pressed {
+                        let action = ACTION_BINDINGS.lock().unwrap().get(shortcut).cloned();
+                        if let Some(action) = action {
+                            run_hotkey_action(app, &action);
+                        }
+                    }
+                })
+                .build(),
+        )
         .setup(|app| {
             if cfg!(debug_assertions) {
                 app.handle().plugin(
@@ -17,10 +31,24 @@ pub fn run() {
                 )?;
             }
 
+            for error in register_hotkeys(app.handle().clone()).unwrap_or_default() {
+                log::warn!("Failed to register hotkey '{}': {}", error.action, error.message);
+            }
+
+            start_ipc_server();
+
             // System Tray Setup
             let quit_i = MenuItem::with_id(app, "quit", "Quit Money Machine", true, None::<&str>)?;
-            let show_i = MenuItem::with_id(app, "show", "Show/Hide Dashboard", true, None::<&str>)?;
-            let menu = Menu::with_items(app, &[&show_i, &quit_i])?;
+            let show_i = MenuItem::with_id(app, "show", "Hide Dashboard", true, None::<&str>)?;
+            let keep_alive_i =
+                CheckMenuItem::with_id(app, "keep_alive", "Keep System Awake", true, false, None::<&str>)?;
+            let lock_i = MenuItem::with_id(app, "lock", "Lock Vault", true, None::<&str>)?;
+            let menu = Menu::with_items(app, &[&show_i, &keep_alive_i, &lock_i, &quit_i])?;
+
+            app.manage(TrayMenuHandles {
+                show_i: show_i.clone(),
+                keep_alive_i: keep_alive_i.clone(),
+            });
 
             let _tray = TrayIconBuilder::with_id("tray")
                 .menu(&menu)
@@ -34,6 +62,14 @@ pub fn run() {
                         if let Some(window) = app.get_webview_window("main") {
                             let _ = window.toggle_visibility();
                         }
+                        sync_tray_menu(app);
+                    }
+                    "keep_alive" => {
+                        toggle_keep_alive_handle();
+                        sync_tray_menu(app);
+                    }
+                    "lock" => {
+                        lock_session(app);
                     }
                     _ => {}
                 })
@@ -46,11 +82,23 @@ pub fn run() {
                         if let Some(window) = app.get_webview_window("main") {
                             let _ = window.toggle_visibility();
                         }
+                        sync_tray_menu(app);
                     }
                     _ => {}
                 })
                 .build(app)?;
 
+            if let Some(window) = app.get_webview_window("main") {
+                let app_handle = app.handle().clone();
+                window.on_window_event(move |event| {
+                    if let tauri::WindowEvent::Focused(_) = event {
+                        sync_tray_menu(&app_handle);
+                    }
+                });
+            }
+
+            sync_tray_menu(&app.handle().clone());
+
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
@@ -60,7 +108,13 @@ pub fn run() {
             disable_keep_alive,
             store_api_key,
             get_api_key,
-            delete_api_key
+            delete_api_key,
+            unlock_vault,
+            lock_vault,
+            register_hotkeys,
+            update_hotkey,
+            record_activity,
+            configure_session_timeout
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -103,7 +157,7 @@ use std::sync::Mutex;
 static KEEP_AWAKE_HANDLE: Lazy<Mutex<Option<KeepAwake>>> = Lazy::new(|| Mutex::new(None));
 
 #[tauri::command]
-fn enable_keep_alive() -> Result<String, String> {
+fn enable_keep_alive(app: AppHandle) -> Result<String, String> {
     let mut handle = KEEP_AWAKE_HANDLE.lock().map_err(|e| e.to_string())?;
 
     if handle.is_some() {
@@ -117,12 +171,14 @@ fn enable_keep_alive() -> Result<String, String> {
         .sleep(true); // Prevent sleep
 
     *handle = Some(awake);
+    drop(handle);
     log::info!("✅ OS Keep-Alive enabled");
+    sync_tray_menu(&app);
     Ok("Keep-Alive enabled".to_string())
 }
 
 #[tauri::command]
-fn disable_keep_alive() -> Result<String, String> {
+fn disable_keep_alive(app: AppHandle) -> Result<String, String> {
     let mut handle = KEEP_AWAKE_HANDLE.lock().map_err(|e| e.to_string())?;
 
     if handle.is_none() {
@@ -130,10 +186,25 @@ fn disable_keep_alive() -> Result<String, String> {
     }
 
     *handle = None; // Dropping the KeepAwake handle re-enables sleep
+    drop(handle);
     log::info!("💤 OS Keep-Alive disabled");
+    sync_tray_menu(&app);
     Ok("Keep-Alive disabled".to_string())
 }
 
+/// Flips Keep-Alive on/off, for callers (tray, hotkeys) that just want the opposite
+/// of whatever's currently active rather than the enable/disable commands' explicit state.
+fn toggle_keep_alive_handle() {
+    let mut handle = KEEP_AWAKE_HANDLE.lock().unwrap();
+    if handle.is_some() {
+        *handle = None;
+        log::info!("💤 OS Keep-Alive disabled");
+    } else if let Ok(awake) = KeepAwake::new() {
+        *handle = Some(awake.display(false).idle(true).sleep(true));
+        log::info!("✅ OS Keep-Alive enabled");
+    }
+}
+
 // ============================================================
 // SECURE API KEY STORAGE (OS Keychain)
 // ============================================================
@@ -142,12 +213,268 @@ use keyring::Entry;
 
 const SERVICE_NAME: &str = "money-machine";
 
+// ------------------------------------------------------------
+// Encrypted vault: a master password unlocks each API key instead of
+// trusting any process running as the user to read the raw keychain entry.
+// ------------------------------------------------------------
+
+use argon2::Argon2;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use chacha20poly1305::{
+    aead::{rand_core::RngCore, Aead, AeadCore, KeyInit, OsRng},
+    Key, XChaCha20Poly1305, XNonce,
+};
+use std::time::Instant;
+use zeroize::Zeroizing;
+
+const VAULT_SALT_ENTRY: &str = "__vault_salt__";
+const VAULT_VERIFIER_ENTRY: &str = "__vault_verifier__";
+const VAULT_VERIFIER_PLAINTEXT: &[u8] = b"money-machine-vault-v1";
+const ARGON2_SALT_LEN: usize = 16;
+const XCHACHA_NONCE_LEN: usize = 24;
+const MAX_PASSWORD_ATTEMPTS: u32 = 5;
+const PASSWORD_ATTEMPT_BACKOFF_SECS: u64 = 30;
+
+static VAULT_KEY: Lazy<Mutex<Option<Zeroizing<[u8; 32]>>>> = Lazy::new(|| Mutex::new(None));
+
+/// Tracks failed master-password guesses, whether made through `unlock_vault`
+/// or `get_api_key`'s direct password path. There's exactly one master
+/// password for the whole vault, so this is deliberately a single global
+/// counter rather than one per stored key — keying it per entry would let an
+/// attacker get `MAX_PASSWORD_ATTEMPTS` free guesses per stored secret.
+struct PasswordAttempts {
+    failures: u32,
+    locked_until: Option<Instant>,
+    /// Bumped every time `failures` resets to zero (a correct guess, or a
+    /// fresh count after a backoff window elapses). Lets a delayed
+    /// `cancel_password_attempt` tell "the reservation I'm rolling back" from
+    /// "a real failure recorded in a window that started after mine" instead
+    /// of blindly decrementing whatever `failures` happens to be by then.
+    epoch: u64,
+}
+
+static PASSWORD_ATTEMPTS: Lazy<Mutex<PasswordAttempts>> = Lazy::new(|| {
+    Mutex::new(PasswordAttempts {
+        failures: 0,
+        locked_until: None,
+        epoch: 0,
+    })
+});
+
+/// Reserves one guess against the budget before the caller does any slow
+/// derivation/verification work, and returns the epoch that reservation was
+/// made in. Counting the attempt here rather than in `finish_password_attempt`
+/// closes a check-then-act race: if the count were only incremented after
+/// `derive_vault_key`/`verify_vault_key` finished, concurrent guesses could
+/// all pass this check before any of them landed, multiplying the real
+/// budget by however many ran in parallel.
+fn begin_password_attempt() -> Result<u64, String> {
+    let mut attempts = PASSWORD_ATTEMPTS.lock().unwrap();
+    if let Some(until) = attempts.locked_until {
+        let now = Instant::now();
+        if now < until {
+            return Err(format!(
+                "Too many failed master-password attempts; try again in {}s",
+                (until - now).as_secs()
+            ));
+        }
+        // Backoff window elapsed; this guess starts a fresh count.
+        attempts.locked_until = None;
+        attempts.failures = 0;
+        attempts.epoch += 1;
+    }
+
+    attempts.failures += 1;
+    if attempts.failures >= MAX_PASSWORD_ATTEMPTS {
+        attempts.locked_until = Some(Instant::now() + Duration::from_secs(PASSWORD_ATTEMPT_BACKOFF_SECS));
+    }
+    Ok(attempts.epoch)
+}
+
+/// Clears the budget after a guess that turned out to be correct. Failed
+/// guesses need no action here — `begin_password_attempt` already counted
+/// them against the budget up front.
+fn finish_password_attempt(succeeded: bool) {
+    if !succeeded {
+        return;
+    }
+    let mut attempts = PASSWORD_ATTEMPTS.lock().unwrap();
+    attempts.failures = 0;
+    attempts.locked_until = None;
+    attempts.epoch += 1;
+}
+
+/// Rolls back a reservation from `begin_password_attempt` for an attempt that
+/// never actually reached a pass/fail verdict — a keyring I/O error or failed
+/// Argon2 derivation is an infrastructure problem, not a wrong guess, and
+/// shouldn't eat into the guess budget. `epoch` must be the value
+/// `begin_password_attempt` returned for this same attempt: if the budget has
+/// since reset (a new epoch), the failure this attempt would undo belongs to
+/// a later, unrelated guess, so it's left alone.
+fn cancel_password_attempt(epoch: u64) {
+    let mut attempts = PASSWORD_ATTEMPTS.lock().unwrap();
+    if attempts.epoch != epoch {
+        return;
+    }
+    if attempts.failures > 0 {
+        attempts.failures -= 1;
+    }
+    if attempts.failures < MAX_PASSWORD_ATTEMPTS {
+        attempts.locked_until = None;
+    }
+}
+
+/// Loads the vault's Argon2id salt from the keychain, generating and persisting
+/// a fresh random one on first use.
+fn vault_salt() -> Result<[u8; ARGON2_SALT_LEN], String> {
+    let entry = Entry::new(SERVICE_NAME, VAULT_SALT_ENTRY).map_err(|e| format!("Keyring error: {}", e))?;
+
+    match entry.get_password() {
+        Ok(encoded) => BASE64
+            .decode(encoded)
+            .map_err(|e| format!("Corrupt vault salt: {}", e))?
+            .try_into()
+            .map_err(|_| "Corrupt vault salt length".to_string()),
+        Err(keyring::Error::NoEntry) => {
+            let mut salt = [0u8; ARGON2_SALT_LEN];
+            OsRng.fill_bytes(&mut salt);
+            entry
+                .set_password(&BASE64.encode(salt))
+                .map_err(|e| format!("Failed to persist vault salt: {}", e))?;
+            Ok(salt)
+        }
+        Err(e) => Err(format!("Keyring error: {}", e)),
+    }
+}
+
+fn derive_vault_key(master_password: &str, salt: &[u8]) -> Result<Zeroizing<[u8; 32]>, String> {
+    let mut key = Zeroizing::new([0u8; 32]);
+    Argon2::default()
+        .hash_password_into(master_password.as_bytes(), salt, &mut *key)
+        .map_err(|e| format!("Key derivation failed: {}", e))?;
+    Ok(key)
+}
+
+/// Serializes the read-or-create sequence in `verify_vault_key` so two
+/// concurrent first-ever unlocks can't both observe a missing verifier and
+/// race to persist one sealed under different passwords. Whichever caller
+/// gets here first creates the verifier; the other then reads it back and is
+/// correctly accepted or rejected against that one true password.
+static VAULT_VERIFIER_LOCK: Mutex<()> = Mutex::new(());
+
+/// Confirms `key` is the correct vault key by sealing (first unlock ever) or
+/// opening (every unlock after) a small known plaintext alongside it. This is
+/// what lets `unlock_vault` reject a wrong password immediately instead of
+/// caching an unusable key that only surfaces as a decrypt failure later.
+fn verify_vault_key(key: &Zeroizing<[u8; 32]>) -> Result<bool, String> {
+    let _guard = VAULT_VERIFIER_LOCK.lock().unwrap();
+
+    let entry =
+        Entry::new(SERVICE_NAME, VAULT_VERIFIER_ENTRY).map_err(|e| format!("Keyring error: {}", e))?;
+    let cipher = XChaCha20Poly1305::new(Key::from_slice(&**key));
+
+    match entry.get_password() {
+        Ok(encoded) => {
+            let record = BASE64
+                .decode(encoded)
+                .map_err(|e| format!("Corrupt vault verifier: {}", e))?;
+            if record.len() < XCHACHA_NONCE_LEN {
+                return Err("Corrupt vault verifier".to_string());
+            }
+            let (nonce, ciphertext) = record.split_at(XCHACHA_NONCE_LEN);
+            match cipher.decrypt(XNonce::from_slice(nonce), ciphertext) {
+                Ok(plaintext) => Ok(plaintext == VAULT_VERIFIER_PLAINTEXT),
+                Err(_) => Ok(false),
+            }
+        }
+        Err(keyring::Error::NoEntry) => {
+            let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+            let ciphertext = cipher
+                .encrypt(&nonce, VAULT_VERIFIER_PLAINTEXT)
+                .map_err(|e| format!("Encryption failed: {}", e))?;
+            let mut record = Vec::with_capacity(nonce.len() + ciphertext.len());
+            record.extend_from_slice(&nonce);
+            record.extend_from_slice(&ciphertext);
+            entry
+                .set_password(&BASE64.encode(record))
+                .map_err(|e| format!("Failed to persist vault verifier: {}", e))?;
+            Ok(true)
+        }
+        Err(e) => Err(format!("Keyring error: {}", e)),
+    }
+}
+
+fn cached_vault_key() -> Result<Zeroizing<[u8; 32]>, String> {
+    VAULT_KEY
+        .lock()
+        .map_err(|e| e.to_string())?
+        .clone()
+        .ok_or_else(|| "Vault is locked; call unlock_vault first".to_string())
+}
+
+#[tauri::command]
+fn unlock_vault(app: AppHandle, master_password: String) -> Result<String, String> {
+    let attempt_epoch = begin_password_attempt()?;
+
+    let salt = match vault_salt() {
+        Ok(salt) => salt,
+        Err(e) => {
+            cancel_password_attempt(attempt_epoch);
+            return Err(e);
+        }
+    };
+    let key = match derive_vault_key(&master_password, &salt) {
+        Ok(key) => key,
+        Err(e) => {
+            cancel_password_attempt(attempt_epoch);
+            return Err(e);
+        }
+    };
+    let verified = match verify_vault_key(&key) {
+        Ok(verified) => verified,
+        Err(e) => {
+            cancel_password_attempt(attempt_epoch);
+            return Err(e);
+        }
+    };
+    finish_password_attempt(verified);
+    if !verified {
+        return Err("Wrong master password".to_string());
+    }
+
+    *VAULT_KEY.lock().map_err(|e| e.to_string())? = Some(key);
+    log::info!("🔓 Vault unlocked");
+
+    let timeout = load_session_timeout(&app);
+    start_session_timer(app, timeout);
+    Ok("Vault unlocked".to_string())
+}
+
+#[tauri::command]
+fn lock_vault(app: AppHandle) -> Result<String, String> {
+    lock_session(&app);
+    Ok("Vault locked".to_string())
+}
+
 #[tauri::command]
 fn store_api_key(key_name: String, key_value: String) -> Result<String, String> {
-    let entry = Entry::new(SERVICE_NAME, &key_name).map_err(|e| format!("Keyring error: {}", e))?;
+    let key = cached_vault_key()?;
+    let salt = vault_salt()?;
+
+    let cipher = XChaCha20Poly1305::new(Key::from_slice(&*key));
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, key_value.as_bytes())
+        .map_err(|e| format!("Encryption failed: {}", e))?;
+
+    let mut record = Vec::with_capacity(salt.len() + nonce.len() + ciphertext.len());
+    record.extend_from_slice(&salt);
+    record.extend_from_slice(&nonce);
+    record.extend_from_slice(&ciphertext);
 
+    let entry = Entry::new(SERVICE_NAME, &key_name).map_err(|e| format!("Keyring error: {}", e))?;
     entry
-        .set_password(&key_value)
+        .set_password(&BASE64.encode(record))
         .map_err(|e| format!("Failed to store key: {}", e))?;
 
     log::info!("🔐 Stored API key: {}", key_name);
@@ -155,12 +482,51 @@ fn store_api_key(key_name: String, key_value: String) -> Result<String, String>
 }
 
 #[tauri::command]
-fn get_api_key(key_name: String) -> Result<String, String> {
+fn get_api_key(key_name: String, master_password: Option<String>) -> Result<String, String> {
     let entry = Entry::new(SERVICE_NAME, &key_name).map_err(|e| format!("Keyring error: {}", e))?;
-
-    entry
+    let encoded = entry
         .get_password()
-        .map_err(|e| format!("Failed to retrieve key: {}", e))
+        .map_err(|e| format!("Failed to retrieve key: {}", e))?;
+    let record = BASE64
+        .decode(encoded)
+        .map_err(|e| format!("Corrupt vault entry: {}", e))?;
+
+    if record.len() < ARGON2_SALT_LEN + XCHACHA_NONCE_LEN {
+        return Err("Corrupt vault entry".to_string());
+    }
+    let (salt, rest) = record.split_at(ARGON2_SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(XCHACHA_NONCE_LEN);
+
+    let via_password = master_password.is_some();
+    let attempt_epoch = if via_password {
+        Some(begin_password_attempt()?)
+    } else {
+        None
+    };
+
+    let key = match master_password {
+        Some(password) => match derive_vault_key(&password, salt) {
+            Ok(key) => key,
+            Err(e) => {
+                if let Some(epoch) = attempt_epoch {
+                    cancel_password_attempt(epoch);
+                }
+                return Err(e);
+            }
+        },
+        None => cached_vault_key()?,
+    };
+
+    let cipher = XChaCha20Poly1305::new(Key::from_slice(&*key));
+    let result = cipher
+        .decrypt(XNonce::from_slice(nonce), ciphertext)
+        .map_err(|_| "Wrong master password".to_string());
+
+    if via_password {
+        finish_password_attempt(result.is_ok());
+    }
+
+    String::from_utf8(result?).map_err(|e| format!("Corrupt vault entry: {}", e))
 }
 
 #[tauri::command]
@@ -174,3 +540,540 @@ fn delete_api_key(key_name: String) -> Result<String, String> {
     log::info!("🗑️ Deleted API key: {}", key_name);
     Ok(format!("Key '{}' deleted", key_name))
 }
+
+// ============================================================
+// GLOBAL SHORTCUTS (Summon the dashboard without touching the window)
+// ============================================================
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::str::FromStr;
+use tauri::{path::BaseDirectory, AppHandle};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+
+const HOTKEYS_CONFIG_FILE: &str = "hotkeys.json";
+
+/// A single global-shortcut binding, in Tauri accelerator syntax (e.g. `CmdOrCtrl+Shift+M`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Hotkey {
+    keys: String,
+    enabled: bool,
+}
+
+type HotkeysConfig = HashMap<String, Hotkey>;
+
+/// One binding that failed to register, reported back to the frontend instead of panicking.
+#[derive(Debug, Clone, Serialize)]
+struct HotkeyError {
+    action: String,
+    message: String,
+}
+
+static ACTION_BINDINGS: Lazy<Mutex<HashMap<Shortcut, String>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn default_hotkeys() -> HotkeysConfig {
+    HashMap::from([
+        (
+            "toggle_dashboard".to_string(),
+            Hotkey {
+                keys: "CmdOrCtrl+Shift+M".to_string(),
+                enabled: true,
+            },
+        ),
+        (
+            "toggle_always_on_top".to_string(),
+            Hotkey {
+                keys: "CmdOrCtrl+Shift+T".to_string(),
+                enabled: true,
+            },
+        ),
+        (
+            "toggle_keep_alive".to_string(),
+            Hotkey {
+                keys: "CmdOrCtrl+Shift+K".to_string(),
+                enabled: false,
+            },
+        ),
+    ])
+}
+
+fn hotkeys_config_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    app.path()
+        .resolve(HOTKEYS_CONFIG_FILE, BaseDirectory::AppConfig)
+        .map_err(|e| format!("Failed to resolve hotkeys config path: {}", e))
+}
+
+fn load_hotkeys_config(app: &AppHandle) -> HotkeysConfig {
+    hotkeys_config_path(app)
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|raw| serde_json::from_str(&raw).ok())
+        .unwrap_or_else(default_hotkeys)
+}
+
+fn save_hotkeys_config(app: &AppHandle, config: &HotkeysConfig) -> Result<(), String> {
+    let path = hotkeys_config_path(app)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("Failed to create config dir: {}", e))?;
+    }
+    let raw = serde_json::to_string_pretty(config)
+        .map_err(|e| format!("Failed to serialize hotkeys config: {}", e))?;
+    fs::write(path, raw).map_err(|e| format!("Failed to write hotkeys config: {}", e))
+}
+
+fn run_hotkey_action(app: &AppHandle, action: &str) {
+    match action {
+        "toggle_dashboard" => {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.toggle_visibility();
+            }
+            sync_tray_menu(app);
+        }
+        "toggle_always_on_top" => {
+            if let Some(window) = app.get_webview_window("main") {
+                if let Ok(state) = window.is_always_on_top() {
+                    let _ = window.set_always_on_top(!state);
+                }
+            }
+        }
+        "toggle_keep_alive" => {
+            toggle_keep_alive_handle();
+            sync_tray_menu(app);
+        }
+        _ => {}
+    }
+}
+
+/// Re-registers every enabled binding from `config`, collecting per-binding failures
+/// (e.g. the OS rejected the combo) instead of bailing out on the first one.
+fn apply_hotkeys(app: &AppHandle, config: &HotkeysConfig) -> Vec<HotkeyError> {
+    let mut errors = Vec::new();
+    let _ = app.global_shortcut().unregister_all();
+
+    let mut bindings = ACTION_BINDINGS.lock().unwrap();
+    bindings.clear();
+
+    for (action, hotkey) in config {
+        if !hotkey.enabled {
+            continue;
+        }
+
+        let shortcut = match Shortcut::from_str(&hotkey.keys) {
+            Ok(shortcut) => shortcut,
+            Err(e) => {
+                errors.push(HotkeyError {
+                    action: action.clone(),
+                    message: format!("Invalid accelerator '{}': {}", hotkey.keys, e),
+                });
+                continue;
+            }
+        };
+
+        match app.global_shortcut().register(shortcut) {
+            Ok(()) => {
+                bindings.insert(shortcut, action.clone());
+            }
+            Err(e) => errors.push(HotkeyError {
+                action: action.clone(),
+                message: e.to_string(),
+            }),
+        }
+    }
+
+    errors
+}
+
+#[tauri::command]
+fn register_hotkeys(app: AppHandle) -> Result<Vec<HotkeyError>, String> {
+    let config = load_hotkeys_config(&app);
+    Ok(apply_hotkeys(&app, &config))
+}
+
+#[tauri::command]
+fn update_hotkey(app: AppHandle, action: String, hotkey: Hotkey) -> Result<Vec<HotkeyError>, String> {
+    let mut config = load_hotkeys_config(&app);
+    config.insert(action, hotkey);
+    save_hotkeys_config(&app, &config)?;
+    Ok(apply_hotkeys(&app, &config))
+}
+
+// ============================================================
+// SESSION AUTO-LOCK (Idle timeout while keep-alive prevents sleep)
+// ============================================================
+
+use std::time::Duration;
+use tauri::Emitter;
+use tokio::{sync::watch, time::sleep};
+
+const SESSION_CONFIG_FILE: &str = "session_timeout.json";
+const DEFAULT_TIMEOUT_SECS: u64 = 900;
+
+/// How the idle clock behaves: a plain countdown from unlock, or one that
+/// resets every time the frontend reports activity via `record_activity`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+enum SessionTimeout {
+    Fixed { timeout_secs: u64 },
+    Activity { timeout_secs: u64 },
+}
+
+impl Default for SessionTimeout {
+    fn default() -> Self {
+        SessionTimeout::Activity {
+            timeout_secs: DEFAULT_TIMEOUT_SECS,
+        }
+    }
+}
+
+/// Signals activity to the running idle timer. Replaced (dropping the old
+/// sender) whenever a new timer is started, which ends the previous task.
+static ACTIVITY_TX: Lazy<Mutex<Option<watch::Sender<()>>>> = Lazy::new(|| Mutex::new(None));
+
+fn session_config_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    app.path()
+        .resolve(SESSION_CONFIG_FILE, BaseDirectory::AppConfig)
+        .map_err(|e| format!("Failed to resolve session config path: {}", e))
+}
+
+fn load_session_timeout(app: &AppHandle) -> SessionTimeout {
+    session_config_path(app)
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|raw| serde_json::from_str(&raw).ok())
+        .unwrap_or_default()
+}
+
+fn save_session_timeout(app: &AppHandle, timeout: &SessionTimeout) -> Result<(), String> {
+    let path = session_config_path(app)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("Failed to create config dir: {}", e))?;
+    }
+    let raw = serde_json::to_string_pretty(timeout)
+        .map_err(|e| format!("Failed to serialize session timeout: {}", e))?;
+    fs::write(path, raw).map_err(|e| format!("Failed to write session timeout: {}", e))
+}
+
+/// Hides the window, drops the in-memory vault key, and disables keep-alive —
+/// whether triggered by idle expiry or an explicit `lock_vault` call.
+fn lock_session(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.hide();
+    }
+    *VAULT_KEY.lock().unwrap() = None;
+    *KEEP_AWAKE_HANDLE.lock().unwrap() = None;
+    // Stop whatever idle timer is running. `lock_session` is the one place
+    // every lock path (timer expiry, `lock_vault`, the tray's "Lock Vault"
+    // item) converges, so clearing it here — rather than in each caller —
+    // is what keeps a superseded timer from firing a second, spurious lock.
+    *ACTIVITY_TX.lock().unwrap() = None;
+    let _ = app.emit("session-locked", ());
+    log::info!("🔒 Session locked");
+    sync_tray_menu(app);
+}
+
+/// Starts the idle timer for `timeout`, replacing any timer already running.
+fn start_session_timer(app: AppHandle, timeout: SessionTimeout) {
+    let (tx, mut rx) = watch::channel(());
+    *ACTIVITY_TX.lock().unwrap() = Some(tx);
+
+    tauri::async_runtime::spawn(async move {
+        match timeout {
+            SessionTimeout::Fixed { timeout_secs } => {
+                tokio::select! {
+                    _ = sleep(Duration::from_secs(timeout_secs)) => {
+                        lock_session(&app);
+                    }
+                    _ = async { while rx.changed().await.is_ok() {} } => {
+                        // Superseded by a newer timer before the fixed deadline passed.
+                    }
+                }
+            }
+            SessionTimeout::Activity { timeout_secs } => loop {
+                tokio::select! {
+                    _ = sleep(Duration::from_secs(timeout_secs)) => {
+                        lock_session(&app);
+                        break;
+                    }
+                    result = rx.changed() => {
+                        if result.is_err() {
+                            break; // Superseded by a newer timer.
+                        }
+                    }
+                }
+            },
+        }
+    });
+}
+
+#[tauri::command]
+fn record_activity() -> Result<(), String> {
+    if let Some(tx) = ACTIVITY_TX.lock().map_err(|e| e.to_string())?.as_ref() {
+        let _ = tx.send(());
+    }
+    Ok(())
+}
+
+#[tauri::command]
+fn configure_session_timeout(app: AppHandle, timeout: SessionTimeout) -> Result<(), String> {
+    save_session_timeout(&app, &timeout)?;
+    start_session_timer(app, timeout);
+    Ok(())
+}
+
+// ============================================================
+// CLI COMPANION SOCKET (Serve keys to the `money-machine-cli` binary)
+// ============================================================
+
+// The CLI companion talks to the GUI over a Unix domain socket, so the whole
+// subsystem below is Unix-only; on Windows `start_ipc_server` is a no-op
+// stub further down and `money-machine-cli`'s `main` only reports that the
+// companion isn't supported (see its own `#[cfg(unix)]` gates).
+#[cfg(unix)]
+use ipc::{IpcRequest, IpcResponse};
+#[cfg(unix)]
+use std::io::{BufRead, BufReader, Read, Write};
+#[cfg(unix)]
+use std::os::unix::fs::MetadataExt;
+#[cfg(unix)]
+use std::os::unix::net::{UnixListener, UnixStream};
+
+#[cfg(unix)]
+fn handle_ipc_request(request: IpcRequest) -> IpcResponse {
+    if cached_vault_key().is_err() {
+        return IpcResponse::Err("Vault is locked".to_string());
+    }
+
+    match request {
+        IpcRequest::Get { key_name } => match get_api_key(key_name, None) {
+            Ok(value) => IpcResponse::Ok(value),
+            Err(e) => IpcResponse::Err(e),
+        },
+        IpcRequest::Store { key_name, key_value } => match store_api_key(key_name, key_value) {
+            Ok(message) => IpcResponse::Ok(message),
+            Err(e) => IpcResponse::Err(e),
+        },
+    }
+}
+
+/// Reads a single newline-terminated request line, refusing anything longer
+/// than `ipc::MAX_LINE_BYTES` instead of growing the buffer unbounded.
+#[cfg(unix)]
+fn read_request_line(reader: &mut impl BufRead) -> Option<String> {
+    let mut line = String::new();
+    let read = reader.take(ipc::MAX_LINE_BYTES).read_line(&mut line).ok()?;
+    if read == 0 {
+        return None;
+    }
+    if !line.ends_with('\n') {
+        log::warn!("Rejected oversized or unterminated CLI companion request");
+        return None;
+    }
+    Some(line)
+}
+
+#[cfg(unix)]
+fn handle_ipc_connection(stream: UnixStream, owner_uid: u32) {
+    match stream.peer_cred() {
+        Ok(cred) if cred.uid == owner_uid => {}
+        Ok(cred) => {
+            log::warn!("Rejected CLI companion connection from uid {}", cred.uid);
+            return;
+        }
+        Err(e) => {
+            log::warn!("Failed to verify CLI companion peer credentials: {}", e);
+            return;
+        }
+    }
+
+    let mut reader = BufReader::new(match stream.try_clone() {
+        Ok(clone) => clone,
+        Err(_) => return,
+    });
+    let mut writer = stream;
+
+    let Some(line) = read_request_line(&mut reader) else {
+        return;
+    };
+
+    let response = match serde_json::from_str::<IpcRequest>(&line) {
+        Ok(request) => handle_ipc_request(request),
+        Err(e) => IpcResponse::Err(format!("Malformed request: {}", e)),
+    };
+
+    if let Ok(mut payload) = serde_json::to_string(&response) {
+        payload.push('\n');
+        let _ = writer.write_all(payload.as_bytes());
+    }
+}
+
+#[cfg(unix)]
+fn start_ipc_server() {
+    let path = match ipc::ensure_socket_dir() {
+        Ok(dir) => dir.join(ipc::SOCKET_NAME),
+        Err(e) => {
+            log::error!("Failed to prepare CLI companion socket directory: {}", e);
+            return;
+        }
+    };
+
+    if path.exists() {
+        // A stale socket from a crashed previous run won't accept connections;
+        // one that's still live belongs to another running instance (or, since
+        // this directory is private to our own uid, can't belong to an
+        // attacker) — either way it's fatal, never silently replaced.
+        match UnixStream::connect(&path) {
+            Ok(_) => {
+                log::error!(
+                    "CLI companion socket {} is already in use; refusing to start a second instance",
+                    path.display()
+                );
+                return;
+            }
+            Err(_) => {
+                if let Err(e) = std::fs::remove_file(&path) {
+                    log::error!("Failed to remove stale CLI companion socket: {}", e);
+                    return;
+                }
+            }
+        }
+    }
+
+    let listener = match UnixListener::bind(&path) {
+        Ok(listener) => listener,
+        Err(e) => {
+            log::error!("Failed to start CLI companion socket: {}", e);
+            return;
+        }
+    };
+
+    let owner_uid = match std::fs::metadata(&path) {
+        Ok(meta) => meta.uid(),
+        Err(e) => {
+            log::error!("Failed to stat CLI companion socket: {}", e);
+            return;
+        }
+    };
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            std::thread::spawn(move || handle_ipc_connection(stream, owner_uid));
+        }
+    });
+}
+
+/// The CLI companion binary is Unix-only (it dials a Unix domain socket), so
+/// there's nothing to serve on Windows. Named-pipe support would go here.
+#[cfg(not(unix))]
+fn start_ipc_server() {
+    log::info!("CLI companion socket is not supported on this platform; skipping");
+}
+
+// ============================================================
+// DYNAMIC TRAY MENU (Reflect window visibility and keep-alive/lock state)
+// ============================================================
+
+/// Handles for the tray menu items that change live, kept in managed state
+/// so menu-event and window-event handlers can mutate labels/check states.
+struct TrayMenuHandles {
+    show_i: MenuItem<tauri::Wry>,
+    keep_alive_i: CheckMenuItem<tauri::Wry>,
+}
+
+/// Refreshes the tray menu to match the current window visibility and
+/// Keep-Alive state. Cheap enough to call after every action that could
+/// change either.
+fn sync_tray_menu(app: &AppHandle) {
+    let Some(handles) = app.try_state::<TrayMenuHandles>() else {
+        return;
+    };
+
+    if let Some(window) = app.get_webview_window("main") {
+        let visible = window.is_visible().unwrap_or(true);
+        let label = if visible { "Hide Dashboard" } else { "Show Dashboard" };
+        let _ = handles.show_i.set_text(label);
+    }
+
+    let keep_alive_active = KEEP_AWAKE_HANDLE.lock().unwrap().is_some();
+    let _ = handles.keep_alive_i.set_checked(keep_alive_active);
+}
+
+#[cfg(test)]
+mod password_attempt_tests {
+    use super::*;
+
+    /// `PASSWORD_ATTEMPTS` is a single process-wide static, so exercising the
+    /// whole state machine as one scenario (rather than across several
+    /// `#[test]` fns cargo could run concurrently) is what keeps these
+    /// assertions from treading on each other.
+    fn reset_attempts() {
+        let mut attempts = PASSWORD_ATTEMPTS.lock().unwrap();
+        attempts.failures = 0;
+        attempts.locked_until = None;
+        attempts.epoch = 0;
+    }
+
+    #[test]
+    fn password_attempt_state_machine() {
+        reset_attempts();
+
+        // MAX_PASSWORD_ATTEMPTS consecutive failures trip the lockout.
+        for _ in 0..MAX_PASSWORD_ATTEMPTS - 1 {
+            begin_password_attempt().expect("should not be locked out yet");
+            finish_password_attempt(false);
+        }
+        let locking_epoch = begin_password_attempt().expect("5th attempt should still be allowed");
+        finish_password_attempt(false);
+        assert!(
+            begin_password_attempt().is_err(),
+            "6th attempt should be rejected by the lockout"
+        );
+
+        // Once the backoff window elapses, the next attempt resets the count
+        // and starts a new epoch rather than staying locked forever.
+        {
+            let mut attempts = PASSWORD_ATTEMPTS.lock().unwrap();
+            attempts.locked_until = Some(Instant::now() - Duration::from_secs(1));
+        }
+        let fresh_epoch = begin_password_attempt().expect("lockout window has elapsed");
+        assert_ne!(
+            fresh_epoch, locking_epoch,
+            "a reset after backoff should start a new epoch"
+        );
+        assert!(
+            PASSWORD_ATTEMPTS.lock().unwrap().locked_until.is_none(),
+            "lockout should be cleared once a fresh attempt is made after the window elapses"
+        );
+
+        // A correct guess clears the budget.
+        finish_password_attempt(true);
+        {
+            let attempts = PASSWORD_ATTEMPTS.lock().unwrap();
+            assert_eq!(attempts.failures, 0);
+            assert!(attempts.locked_until.is_none());
+        }
+
+        reset_attempts();
+
+        // cancel_password_attempt rolls back a reservation from the same epoch.
+        let epoch = begin_password_attempt().unwrap();
+        assert_eq!(PASSWORD_ATTEMPTS.lock().unwrap().failures, 1);
+        cancel_password_attempt(epoch);
+        assert_eq!(PASSWORD_ATTEMPTS.lock().unwrap().failures, 0);
+
+        // But a cancel that arrives after the budget has since reset (a stale
+        // epoch) must not erase a failure recorded in the new window.
+        let stale_epoch = begin_password_attempt().unwrap();
+        finish_password_attempt(true); // success resets failures and bumps the epoch
+        begin_password_attempt().unwrap(); // a genuine failure in the new epoch
+        assert_eq!(PASSWORD_ATTEMPTS.lock().unwrap().failures, 1);
+        cancel_password_attempt(stale_epoch);
+        assert_eq!(
+            PASSWORD_ATTEMPTS.lock().unwrap().failures,
+            1,
+            "a stale-epoch cancel must not erase a failure recorded in a later window"
+        );
+
+        reset_attempts();
+    }
+}